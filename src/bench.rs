@@ -0,0 +1,121 @@
+//! Benchmarking a [`Guesser`] against every answer in a dictionary.
+
+use rayon::prelude::*;
+
+use crate::{Guesser, Wordle};
+
+/// How many guesses a game is allowed to run for before [`Wordle::play`]
+/// gives up, purely so the benchmark's histogram can see the tail of
+/// struggling strategies.
+const MAX_HISTOGRAM_GUESSES: usize = 32;
+
+/// How a strategy performed across every answer it was benchmarked on.
+#[derive(Debug, Clone)]
+pub struct BenchmarkSummary {
+    /// Number of games that finished in exactly `i + 1` guesses.
+    pub histogram: [usize; MAX_HISTOGRAM_GUESSES],
+    /// Mean number of guesses, across games that were solved at all.
+    pub mean_guesses: f64,
+    /// Standard deviation of guesses, across games that were solved at all.
+    pub stddev_guesses: f64,
+    /// Fraction of answers that took more than six guesses, or were never
+    /// solved within the histogram's ceiling.
+    pub failure_rate: f64,
+}
+
+/// Plays a fresh `G` (built by `new_guesser`) against every word in
+/// `answers`, in parallel, and summarizes how the strategy did.
+pub fn benchmark<const N: usize, G>(
+    wordle: &Wordle<N>,
+    answers: &[&'static str],
+    new_guesser: impl Fn() -> G + Sync,
+) -> BenchmarkSummary
+where
+    G: Guesser<N>,
+{
+    let results: Vec<Option<usize>> =
+        answers.par_iter().map(|answer| wordle.play(answer, new_guesser())).collect();
+
+    let mut histogram = [0_usize; MAX_HISTOGRAM_GUESSES];
+    let mut solved: Vec<f64> = Vec::new();
+    let mut failures = 0_usize;
+
+    for result in &results {
+        match result {
+            Some(guesses) => {
+                histogram[guesses - 1] += 1;
+                solved.push(*guesses as f64);
+                if *guesses > 6 {
+                    failures += 1;
+                }
+            }
+            None => failures += 1,
+        }
+    }
+
+    let (mean_guesses, stddev_guesses) = if solved.is_empty() {
+        (0.0, 0.0)
+    } else {
+        let mean = solved.iter().sum::<f64>() / solved.len() as f64;
+        let variance =
+            solved.iter().map(|guesses| (guesses - mean).powi(2)).sum::<f64>() / solved.len() as f64;
+        (mean, variance.sqrt())
+    };
+    let failure_rate =
+        if results.is_empty() { 0.0 } else { failures as f64 / results.len() as f64 };
+
+    BenchmarkSummary { histogram, mean_guesses, stddev_guesses, failure_rate }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Guess, Word, Wordle};
+
+    const ALLOWED: &[(&str, usize)] = &[("abcde", 1), ("fghij", 1)];
+
+    /// Guesses the words it was built with, in order, regardless of
+    /// history — enough to drive deterministic `benchmark` results.
+    struct InOrder {
+        words: Vec<Word>,
+        turn: usize,
+    }
+
+    impl Guesser for InOrder {
+        fn guess(&mut self, _history: &[Guess]) -> Word {
+            let word = self.words[self.turn.min(self.words.len() - 1)];
+            self.turn += 1;
+            word
+        }
+    }
+
+    #[test]
+    fn summarizes_a_mixed_result_set() {
+        let wordle: Wordle = Wordle::new(ALLOWED, ALLOWED);
+        let new_guesser = || InOrder {
+            words: vec![wordle.validate("abcde").unwrap(), wordle.validate("fghij").unwrap()],
+            turn: 0,
+        };
+
+        // Every game tries "abcde" first, so it's solved in 1 guess while
+        // "fghij" takes 2.
+        let summary = benchmark(&wordle, &["abcde", "fghij"], new_guesser);
+
+        assert_eq!(summary.histogram[0], 1);
+        assert_eq!(summary.histogram[1], 1);
+        assert_eq!(summary.mean_guesses, 1.5);
+        assert_eq!(summary.failure_rate, 0.0);
+    }
+
+    #[test]
+    fn an_empty_answer_set_reports_zero_rather_than_nan() {
+        let wordle: Wordle = Wordle::new(ALLOWED, ALLOWED);
+        let new_guesser = || InOrder { words: vec![wordle.validate("abcde").unwrap()], turn: 0 };
+
+        let summary = benchmark(&wordle, &[], new_guesser);
+
+        assert_eq!(summary.mean_guesses, 0.0);
+        assert_eq!(summary.stddev_guesses, 0.0);
+        assert_eq!(summary.failure_rate, 0.0);
+    }
+}
@@ -0,0 +1,43 @@
+//! Benchmarks the reference solvers against every answer in the default
+//! dictionary and prints a summary for each.
+
+use wordle::{
+    bench::{self, BenchmarkSummary},
+    solver::{Entropy, ExplorationPool, Naive},
+    Wordle,
+};
+
+fn report(name: &str, summary: &BenchmarkSummary) {
+    println!(
+        "{name}: mean {:.2} guesses, stddev {:.2}, failure rate {:.1}%",
+        summary.mean_guesses,
+        summary.stddev_guesses,
+        summary.failure_rate * 100.0,
+    );
+
+    for (i, &count) in summary.histogram.iter().enumerate() {
+        if count > 0 {
+            println!("  {:>2} guesses: {count}", i + 1);
+        }
+    }
+}
+
+fn main() {
+    let wordle = Wordle::default();
+    let answers: Vec<&'static str> = wordle.answers().map(|(_, word, _)| word).collect();
+
+    report("naive", &bench::benchmark(&wordle, &answers, || Naive::new(&wordle)));
+
+    // The opening guess never depends on history or the answer, so it's
+    // scored once here and threaded into every game's `Entropy` instead of
+    // every one of `answers.len()` parallel games re-scoring the full guess
+    // pool to rediscover the same word.
+    let entropy_pool = ExplorationPool::FullDictionary;
+    let opening_guess = Entropy::opening_guess(&wordle, entropy_pool);
+    report(
+        "entropy",
+        &bench::benchmark(&wordle, &answers, || {
+            Entropy::with_first_guess(&wordle, entropy_pool, opening_guess)
+        }),
+    );
+}
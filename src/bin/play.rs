@@ -0,0 +1,48 @@
+//! An interactive, `Guesser`-free play mode: type guesses on stdin and see
+//! the colored tile history after each turn.
+
+use std::io::{self, Write};
+
+use wordle::{Correctness, Guess, Wordle};
+
+fn main() {
+    let answer = std::env::args().nth(1).expect("usage: play <answer>");
+    let wordle = Wordle::default();
+
+    if !wordle.answers().any(|(_, word, _)| word == answer) {
+        eprintln!("'{answer}' is not a valid answer, try a 5-letter word from the answer list");
+        std::process::exit(1);
+    }
+
+    let mut history: Vec<Guess> = Vec::new();
+    let mut turn = 1;
+
+    while turn <= 6 {
+        print!("guess {turn}/6: ");
+        io::stdout().flush().expect("stdout should be writable");
+
+        let mut line = String::new();
+        io::stdin().read_line(&mut line).expect("stdin should be readable");
+        let guess = line.trim();
+
+        let Some(word) = wordle.validate(guess) else {
+            println!("'{guess}' is not an allowed guess, try again");
+            continue;
+        };
+        let guess = wordle.resolve(word);
+
+        let mask = Correctness::compute::<5>(&answer, guess);
+        history.push(Guess { word: guess.into(), mask });
+        for guess in &history {
+            println!("{guess}");
+        }
+
+        if guess == answer {
+            println!("solved in {turn}!");
+            return;
+        }
+        turn += 1;
+    }
+
+    println!("out of guesses; the answer was '{answer}'");
+}
@@ -1,17 +1,37 @@
 #![allow(clippy::type_complexity)]
 #![allow(clippy::blocks_in_conditions)]
 
-mod solver;
+pub mod bench;
+pub mod solver;
 
-use std::{borrow::Cow, collections::HashSet, num::NonZeroU8};
+use std::{borrow::Cow, collections::HashMap, collections::HashSet, fmt, num::NonZeroU16};
 
-pub const MAX_MASK_ENUM: usize = 5 * 5 * 5 * 5 * 5;
+/// The number of distinct feedback patterns for a word of length `N`: each
+/// position is independently green, yellow, or gray.
+pub const fn max_mask_enum<const N: usize>() -> usize {
+    let mut result = 1;
+    let mut i = 0;
+    while i < N {
+        result *= 3;
+        i += 1;
+    }
+    result
+}
 
-pub trait Guesser {
-    fn guess(&mut self, history: &[Guess]) -> String;
+pub trait Guesser<const N: usize = 5> {
+    fn guess(&mut self, history: &[Guess<'_, N>]) -> Word;
     fn finish(&self, _guesses: usize) {}
 }
 
+/// A validated index into a [`Wordle`]'s allowed-guess list. Obtaining one
+/// from a `Wordle` ([`Wordle::validate`], [`Wordle::allowed_guesses`] or
+/// [`Wordle::answers`]) is the only way to construct it, which makes an
+/// invalid guess unrepresentable and lets [`Guesser`] implementations pass
+/// a cheap `Copy` handle around instead of re-validating and re-allocating
+/// a `String` on every turn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Word(u32);
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Correctness {
     /// Green
@@ -23,7 +43,7 @@ pub enum Correctness {
 }
 
 impl Correctness {
-    fn is_misplaced(letter: u8, answer: &str, used: &mut [bool; 5]) -> bool {
+    fn is_misplaced<const N: usize>(letter: u8, answer: &str, used: &mut [bool; N]) -> bool {
         answer.bytes().enumerate().any(|(i, a)| {
             if a == letter && !used[i] {
                 used[i] = true;
@@ -33,11 +53,11 @@ impl Correctness {
         })
     }
 
-    pub fn compute(answer: &str, guess: &str) -> [Self; 5] {
-        assert_eq!(answer.len(), 5);
-        assert_eq!(guess.len(), 5);
+    pub fn compute<const N: usize>(answer: &str, guess: &str) -> [Self; N] {
+        assert_eq!(answer.len(), N);
+        assert_eq!(guess.len(), N);
 
-        let mut correctness: [Correctness; 5] = [Correctness::Wrong; 5];
+        let mut correctness: [Correctness; N] = [Correctness::Wrong; N];
         let answer_bytes: &[u8] = answer.as_bytes();
         let guess_bytes: &[u8] = guess.as_bytes();
         let mut misplaced = [0_u8; (b'z' - b'a' +1) as usize];
@@ -56,21 +76,43 @@ impl Correctness {
         for (&guess, c) in guess_bytes.iter().zip(correctness.iter_mut()) {
             if *c == Correctness::Wrong && misplaced[(guess - b'a') as usize] > 0 {
                 *c = Correctness::Misplaced;
-                misplaced[(guess - b'a' ) as usize] += 1
+                misplaced[(guess - b'a' ) as usize] -= 1
             }
         }
         correctness
     }
+
+    /// Renders `letter` as an ANSI tile in this correctness's color: green
+    /// for `Correct`, yellow for `Misplaced`, gray for `Wrong`.
+    fn tile(self, letter: u8) -> String {
+        let background = match self {
+            Correctness::Correct => "42",
+            Correctness::Misplaced => "43",
+            Correctness::Wrong => "100",
+        };
+        format!("\x1b[{background}m\x1b[30m {} \x1b[0m", (letter as char).to_ascii_uppercase())
+    }
 }
 
+/// Renders a feedback mask as a row of colored tiles for `word`,
+/// independent of any [`Guess`]; [`Guess`]'s `Display` impl is built on it.
+pub trait ColorizeMask {
+    fn colorize(&self, word: &str) -> String;
+}
+
+impl<const N: usize> ColorizeMask for [Correctness; N] {
+    fn colorize(&self, word: &str) -> String {
+        word.bytes().zip(self.iter()).map(|(letter, c)| c.tile(letter)).collect()
+    }
+}
 
 #[derive( Clone, Copy, PartialEq, Eq)]
 #[repr(transparent)]
-struct PacketCorrectness(NonZeroU8);
+struct PacketCorrectness(NonZeroU16);
 
-impl From<[Correctness; 5]> for PacketCorrectness {
-    fn from(correct: [Correctness; 5]) -> Self {
-        let packed = correct.iter().fold(0, |acc, c| {
+impl<const N: usize> From<[Correctness; N]> for PacketCorrectness {
+    fn from(correct: [Correctness; N]) -> Self {
+        let packed = correct.iter().fold(0_u16, |acc, c| {
             acc * 3 +
             match c {
                 Correctness::Correct => 0,
@@ -78,30 +120,41 @@ impl From<[Correctness; 5]> for PacketCorrectness {
                 Correctness::Wrong => 2
             }
         });
-        Self(NonZeroU8::new(packed + 1).unwrap())
+        Self(NonZeroU16::new(packed + 1).unwrap())
     }
 }
 
-impl From<PacketCorrectness> for u8 {
+impl From<PacketCorrectness> for u16 {
     fn from(this: PacketCorrectness) -> Self {
         this.0.get() -1
     }
 }
 
-pub struct Wordle {
-    dictionary: HashSet<&'static str>
+pub struct Wordle<const N: usize = 5> {
+    /// The full list a guess may be picked from, indexed by [`Word`].
+    allowed_guesses: Vec<&'static str>,
+    /// `allowed_guesses[i]`'s frequency weight, parallel to it.
+    allowed_guess_counts: Vec<usize>,
+    /// `allowed_guesses`, but searchable the other way around.
+    guess_index: HashMap<&'static str, Word>,
+    /// The curated list an answer is drawn from; a subset of
+    /// `allowed_guesses`.
+    answers: HashSet<&'static str>,
+    /// Each entry of `answers`, as its already-validated handle, text, and
+    /// frequency weight.
+    answer_weights: Vec<(Word, &'static str, usize)>,
 }
 
-pub struct Guess<'a> {
+pub struct Guess<'a, const N: usize = 5> {
     pub word: Cow<'a, str>,
-    pub mask: [Correctness; 5]
+    pub mask: [Correctness; N]
 }
 
-impl Guess<'_> {
+impl<const N: usize> Guess<'_, N> {
     pub fn matches(&self, word: &str) -> bool {
-        assert_eq!(word.len(), 5);
-        assert_eq!(self.word.len(), 5);
-        let mut used: [bool; 5] = [false; 5];
+        assert_eq!(word.len(), N);
+        assert_eq!(self.word.len(), N);
+        let mut used: [bool; N] = [false; N];
 
         // check corrected letters
         for (i, (a, g)) in word.bytes().zip(self.word.bytes()).enumerate() {
@@ -111,54 +164,109 @@ impl Guess<'_> {
                 }
                 used[i] = true;
             } else if self.mask[i] == Correctness::Correct {
-                    return false;
-                }
+                return false;
             }
-            // check misplaced letters
-            for (g, e) in self.word.bytes().zip(self.mask.iter()) {
-                if *e == Correctness::Correct {
-                    continue;
-                }
-                if Correctness::is_misplaced(g, word, &mut used) != (*e == Correctness::Misplaced) {
-                    return false;
-                }
+        }
+        // check misplaced letters
+        for (g, e) in self.word.bytes().zip(self.mask.iter()) {
+            if *e == Correctness::Correct {
+                continue;
+            }
+            if Correctness::is_misplaced(g, word, &mut used) != (*e == Correctness::Misplaced) {
+                return false;
             }
-            true
         }
+        true
     }
+}
+
+impl<const N: usize> fmt::Display for Guess<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.mask.colorize(&self.word))
+    }
+}
 
-impl Default for Wordle {
+impl Default for Wordle<5> {
     fn default() -> Self {
-        Self::new()
+        Self::new(DICTIONARY, DICTIONARY)
     }
 }
 
-impl Wordle {
-    pub fn new() -> Self {
-        Self {
-            dictionary: HashSet::from_iter(DICTIONARY.lines().iter().copied()
-                .map(|(word, _)| word))
-        }
+impl<const N: usize> Wordle<N> {
+    /// `allowed_guesses` is the (larger) list a guess is validated against;
+    /// `answers` is the (smaller, curated) list an answer is drawn from.
+    /// Both are in `DICTIONARY`'s own `(word, count)` format.
+    pub fn new(
+        allowed_guesses: &'static [(&'static str, usize)],
+        answers: &'static [(&'static str, usize)],
+    ) -> Self {
+        let allowed_guess_counts: Vec<usize> = allowed_guesses.iter().map(|&(_, count)| count).collect();
+        let allowed_guesses: Vec<&'static str> =
+            allowed_guesses.iter().copied().map(|(word, _)| word).collect();
+        let guess_index: HashMap<&'static str, Word> = allowed_guesses
+            .iter()
+            .enumerate()
+            .map(|(i, &word)| (word, Word(i as u32)))
+            .collect();
+        let answer_weights: Vec<(Word, &'static str, usize)> = answers
+            .iter()
+            .copied()
+            .map(|(word, count)| {
+                let handle = *guess_index.get(word).expect("answers must be a subset of allowed_guesses");
+                (handle, word, count)
+            })
+            .collect();
+        let answers: HashSet<&'static str> = answer_weights.iter().map(|&(_, word, _)| word).collect();
+        Self { allowed_guesses, allowed_guess_counts, guess_index, answers, answer_weights }
+    }
+
+    /// Validates `word` against the allowed-guess list, handing back a
+    /// cheap handle in place of the raw string if it's actually allowed.
+    pub fn validate(&self, word: &str) -> Option<Word> {
+        self.guess_index.get(word).copied()
     }
 
-    pub fn play<G: Guesser>(&self, answer: &'static str, mut guesser: G) -> Option<usize> {
-        let mut history: Vec<Guess> = Vec::new();
+    /// Resolves a [`Word`] previously validated by this `Wordle` back to
+    /// its text.
+    pub fn resolve(&self, word: Word) -> &'static str {
+        self.allowed_guesses[word.0 as usize]
+    }
+
+    /// Every allowed guess as an already-validated handle, its text, and its
+    /// frequency weight, so a `Guesser` can build its candidate pool from
+    /// *this* `Wordle`'s own lists instead of assuming the global
+    /// `DICTIONARY`, which may use a different word length or a different
+    /// curated list entirely.
+    pub fn allowed_guesses(&self) -> impl Iterator<Item = (Word, &'static str, usize)> + '_ {
+        self.allowed_guesses
+            .iter()
+            .zip(self.allowed_guess_counts.iter())
+            .enumerate()
+            .map(|(i, (&word, &count))| (Word(i as u32), word, count))
+    }
+
+    /// Every candidate answer this `Wordle` could draw from, as an
+    /// already-validated handle, its text, and its frequency weight — the
+    /// set a `Guesser` should narrow down each turn, as distinct from the
+    /// larger `allowed_guesses()` it may spend a guess exploring.
+    pub fn answers(&self) -> impl Iterator<Item = (Word, &'static str, usize)> + '_ {
+        self.answer_weights.iter().copied()
+    }
+
+    pub fn play<G: Guesser<N>>(&self, answer: &'static str, mut guesser: G) -> Option<usize> {
+        debug_assert!(self.answers.contains(answer), "'{}' is not a valid answer", answer);
+        let mut history: Vec<Guess<N>> = Vec::new();
 
         // Allow more than six guesses for distribution purposes
         for i in 1..=32 {
-            let guess: String = guesser.guess(&history);
+            let guess: &'static str = self.resolve(guesser.guess(&history));
             if guess == answer {
                 guesser.finish(i);
                 return Some(i);
             }
-            assert!(
-                self.dictionary.contains(&*guess),
-                "guess '{}' is not in the dictionary",
-                guess
-            );
-            let correctness: [Correctness; 5] = Correctness::compute(answer, &guess);
+            let correctness: [Correctness; N] = Correctness::compute(answer, guess);
             history.push(Guess {
-                word: Cow::Owned(guess),
+                word: Cow::Borrowed(guess),
                 mask: correctness,
             });
         }
@@ -224,4 +332,45 @@ fn from_jon() {
     check!("abcde" + [M M M M M] allows "eabcd");
     check!("baaa" + [W C M W W] allows "aaccc");
     check!("baaa" + [W C M W W] disallows "caacc");
+}
+
+#[cfg(test)]
+mod correctness_compute_tests {
+    use super::*;
+
+    /// A guess's repeated letter must only be marked `Misplaced` as many
+    /// times as the answer has unmatched copies left; any further
+    /// occurrence is `Wrong`, not `Misplaced` again.
+    #[test]
+    fn repeated_letter_is_misplaced_only_as_many_times_as_the_answer_has_spare_copies() {
+        // "aabbc" has two 'a's; "ababa" guesses 'a' three times, so one is
+        // `Correct`, one is `Misplaced`, and the third (the answer's 'a's
+        // already exhausted) is `Wrong`.
+        let mask = Correctness::compute::<5>("aabbc", "ababa");
+        assert_eq!(
+            mask,
+            [
+                Correctness::Correct,
+                Correctness::Misplaced,
+                Correctness::Misplaced,
+                Correctness::Correct,
+                Correctness::Wrong,
+            ]
+        );
+    }
+
+    /// `Correctness::compute` must always agree with `Guess::matches` on
+    /// the very answer it was computed against — every solver in this
+    /// crate relies on that invariant.
+    #[test]
+    fn compute_is_self_consistent_with_matches_on_repeated_letter_answers() {
+        for (answer, guess) in [("aabbc", "ababa"), ("sassy", "assay"), ("magic", "amaze")] {
+            let mask = Correctness::compute::<5>(answer, guess);
+            let guessed = Guess { word: Cow::Borrowed(guess), mask };
+            assert!(
+                guessed.matches(answer),
+                "compute({answer:?}, {guess:?}) = {mask:?} isn't self-consistent with matches"
+            );
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,238 @@
+//! An information-theoretic [`Guesser`] that scores each candidate guess by
+//! how much it narrows down the remaining answer set, blended with a prior
+//! so the solver favours a likely answer late in the game.
+
+use std::collections::HashMap;
+
+use crate::{max_mask_enum, Correctness, Guess, Guesser, PacketCorrectness, Word, Wordle};
+
+/// How much weight the frequency prior gets relative to raw expected
+/// information. At `0.0` the solver is a pure entropy-maximizer; turning
+/// this up trades a little information for picking words that are
+/// themselves more likely to be the answer.
+const PRIOR_WEIGHT: f64 = 0.05;
+
+/// Whether the exploratory guess is scored against the full dictionary or
+/// only against the words still consistent with history.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ExplorationPool {
+    /// Score every allowed guess, even ones already ruled out as an answer.
+    FullDictionary,
+    /// Only score words that could still be the answer.
+    RemainingCandidates,
+}
+
+/// A candidate word paired with its frequency-derived prior weight.
+#[derive(Clone, Copy)]
+struct Candidate {
+    handle: Word,
+    text: &'static str,
+    weight: f64,
+}
+
+pub struct Entropy<const N: usize = 5> {
+    /// Every possible answer, weighted by frequency — the set this solver's
+    /// entropy buckets are computed over each turn, as distinct from the
+    /// larger `guess_pool` it may spend a guess exploring.
+    candidates: Vec<Candidate>,
+    /// Every allowed guess, each already paired with its prior — its weight
+    /// among `candidates` if it could be the answer, or a floor weight if
+    /// it's guess-only — computed once here so `score` never has to
+    /// re-derive it by scanning `candidates` on the hot path.
+    guess_pool: Vec<Candidate>,
+    pool: ExplorationPool,
+    /// The solver's opening guess never depends on history, so a caller
+    /// that's about to build many `Entropy`s against the same `wordle`/
+    /// `pool` (e.g. [`bench::benchmark`](crate::bench::benchmark), one
+    /// instance per game) should compute it once via [`Self::opening_guess`]
+    /// and thread it through [`Self::with_first_guess`] instead of letting
+    /// every instance redo that scan over the full guess pool.
+    first_guess: Option<Word>,
+}
+
+impl<const N: usize> Entropy<N> {
+    /// `wordle`'s own answer list is the set this solver narrows down each
+    /// turn; its (larger) allowed-guess list is what it may spend a turn
+    /// exploring.
+    pub fn new(wordle: &Wordle<N>, pool: ExplorationPool) -> Self {
+        let total: f64 = wordle.answers().map(|(_, _, count)| count as f64).sum();
+        let candidates: Vec<Candidate> = wordle
+            .answers()
+            .map(|(handle, text, count)| Candidate { handle, text, weight: count as f64 / total })
+            .collect();
+        let priors: HashMap<&'static str, f64> =
+            candidates.iter().map(|candidate| (candidate.text, candidate.weight)).collect();
+        // A guess-only word (never a possible answer) still needs a nonzero
+        // weight so its `log2` prior term doesn't blow up to `-inf`, but the
+        // floor has to stay small *relative to a real candidate's weight*
+        // rather than an absolute constant — `f64::MIN_POSITIVE`'s log2 is
+        // around -1022, which swamps the at-most-`log2(243)`-bit entropy
+        // term it's blended with and makes every guess-only word
+        // unconditionally worse than every candidate.
+        let floor_weight = candidates
+            .iter()
+            .map(|candidate| candidate.weight)
+            .fold(f64::INFINITY, f64::min)
+            / 1e6;
+        let guess_pool: Vec<Candidate> = wordle
+            .allowed_guesses()
+            .map(|(handle, text, _)| {
+                let weight = priors.get(text).copied().unwrap_or(floor_weight);
+                Candidate { handle, text, weight }
+            })
+            .collect();
+        Self { candidates, guess_pool, pool, first_guess: None }
+    }
+
+    /// Scores `wordle`'s full guess pool once to find the constant opening
+    /// guess for `pool`, so a caller about to build many instances (one per
+    /// game) only pays for that scan a single time; see
+    /// [`Self::with_first_guess`].
+    pub fn opening_guess(wordle: &Wordle<N>, pool: ExplorationPool) -> Word {
+        Self::new(wordle, pool).guess(&[])
+    }
+
+    /// Like [`Self::new`], but seeded with an opening guess already computed
+    /// by [`Self::opening_guess`], so this instance's first turn returns it
+    /// directly instead of re-scoring the guess pool from scratch.
+    pub fn with_first_guess(wordle: &Wordle<N>, pool: ExplorationPool, first_guess: Word) -> Self {
+        Self { first_guess: Some(first_guess), ..Self::new(wordle, pool) }
+    }
+
+    /// The candidates still consistent with `history`.
+    fn remaining(&self, history: &[Guess<'_, N>]) -> Vec<Candidate> {
+        self.candidates
+            .iter()
+            .copied()
+            .filter(|candidate| history.iter().all(|guess| guess.matches(candidate.text)))
+            .collect()
+    }
+
+    /// Scores `guess` by the information it's expected to reveal about
+    /// `remaining`, blended with how likely `guess` itself is to be the
+    /// answer — `guess.weight` is its prior among every candidate answer,
+    /// not just the ones still alive, so a word outside `remaining` isn't
+    /// automatically treated as near-impossible.
+    fn score(&self, guess: &Candidate, remaining: &[Candidate], remaining_weight: f64) -> f64 {
+        let mut buckets = vec![0.0_f64; max_mask_enum::<N>()];
+        for candidate in remaining {
+            let mask = PacketCorrectness::from(Correctness::compute::<N>(candidate.text, guess.text));
+            buckets[u16::from(mask) as usize] += candidate.weight;
+        }
+
+        let entropy: f64 = buckets
+            .iter()
+            .copied()
+            .filter(|&weight| weight > 0.0)
+            .map(|weight| {
+                let p = weight / remaining_weight;
+                -p * p.log2()
+            })
+            .sum();
+
+        entropy + PRIOR_WEIGHT * guess.weight.log2()
+    }
+}
+
+impl<const N: usize> Guesser<N> for Entropy<N> {
+    fn guess(&mut self, history: &[Guess<'_, N>]) -> Word {
+        if history.is_empty() {
+            if let Some(first) = self.first_guess {
+                return first;
+            }
+        }
+
+        let remaining = self.remaining(history);
+        let remaining_weight: f64 = remaining.iter().map(|candidate| candidate.weight).sum();
+
+        let pool: &[Candidate] = match self.pool {
+            ExplorationPool::FullDictionary => &self.guess_pool,
+            ExplorationPool::RemainingCandidates => &remaining,
+        };
+
+        let best = pool
+            .iter()
+            .map(|candidate| (*candidate, self.score(candidate, &remaining, remaining_weight)))
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).expect("scores are never NaN"))
+            .expect("the candidate pool is never empty")
+            .0
+            .handle;
+
+        if history.is_empty() {
+            self.first_guess = Some(best);
+        }
+
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(text: &'static str, weight: f64) -> Candidate {
+        // `Word`'s field is only reachable from `crate` and its descendant
+        // modules; its value doesn't matter here since `score` never
+        // resolves it.
+        Candidate { handle: Word(0), text, weight }
+    }
+
+    #[test]
+    fn prior_uses_the_precomputed_weight_not_just_survivors() {
+        let entropy = Entropy::<5> {
+            candidates: vec![candidate("abcde", 0.5), candidate("fghij", 0.5)],
+            guess_pool: vec![candidate("abcde", 0.5), candidate("fghij", 0.5)],
+            pool: ExplorationPool::FullDictionary,
+            first_guess: None,
+        };
+
+        // "fghij" has already been ruled out of `remaining`, but its
+        // precomputed prior is still half the weight of the full answer
+        // list - it should reflect that, not collapse to
+        // `f64::MIN_POSITIVE`-level noise.
+        let remaining = vec![candidate("abcde", 0.5)];
+        let guess = candidate("fghij", 0.5);
+        let score = entropy.score(&guess, &remaining, 0.5);
+
+        assert!(score.is_finite());
+        assert!(score > -10.0, "prior should come from the precomputed weight, got {score}");
+    }
+
+    #[test]
+    fn guess_only_floor_weight_stays_well_above_prior_weight_swamping_every_entropy_gap() {
+        let wordle: Wordle<5> = Wordle::new(
+            &[("abcde", 1), ("fghij", 1), ("zzzzz", 1)],
+            &[("abcde", 1), ("fghij", 1)],
+        );
+        let entropy = Entropy::new(&wordle, ExplorationPool::FullDictionary);
+
+        let zzzzz = entropy
+            .guess_pool
+            .iter()
+            .find(|candidate| candidate.text == "zzzzz")
+            .expect("zzzzz is an allowed guess");
+
+        // A maximally informative guess earns at most `log2(3^5)` bits of
+        // entropy (every tile green/yellow/gray); the guess-only floor's
+        // prior penalty must stay much smaller than that, not swamp it the
+        // way `f64::MIN_POSITIVE`'s ~-1022 log2 did.
+        let max_entropy_bits = (3_f64.powi(5)).log2();
+        assert!(
+            PRIOR_WEIGHT * zzzzz.weight.log2() > -max_entropy_bits,
+            "floor weight's prior penalty ({}) swamps the maximum possible entropy gain ({max_entropy_bits})",
+            PRIOR_WEIGHT * zzzzz.weight.log2()
+        );
+    }
+
+    #[test]
+    fn with_first_guess_returns_the_seeded_opening_guess_without_rescoring() {
+        let wordle: Wordle<5> =
+            Wordle::new(&[("abcde", 1), ("fghij", 1)], &[("abcde", 1), ("fghij", 1)]);
+        let pool = ExplorationPool::FullDictionary;
+
+        let opening = Entropy::opening_guess(&wordle, pool);
+        let mut entropy = Entropy::with_first_guess(&wordle, pool, opening);
+
+        assert_eq!(entropy.guess(&[]), opening);
+    }
+}
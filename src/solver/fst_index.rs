@@ -0,0 +1,228 @@
+//! Candidate enumeration backed by a finite-state transducer over a
+//! dictionary, so a [`Guesser`](crate::Guesser) can stream the words still
+//! consistent with a guess history in time proportional to shared prefixes
+//! instead of scanning the dictionary.
+
+use fst::{Automaton, IntoStreamer, Set, Streamer};
+
+use crate::{Correctness, Guess};
+
+/// A dictionary of `N`-letter words compiled into a transducer once at
+/// startup.
+pub struct FstIndex<const N: usize = 5> {
+    set: Set<Vec<u8>>,
+}
+
+impl<const N: usize> FstIndex<N> {
+    /// Compiles the transducer over `words`. This is the expensive part, so
+    /// callers should build one `FstIndex` and reuse it across turns (and
+    /// games).
+    pub fn new(words: impl IntoIterator<Item = &'static str>) -> Self {
+        let mut words: Vec<&'static str> = words.into_iter().collect();
+        words.sort_unstable();
+        let set =
+            Set::from_iter(words).expect("dictionary contains a duplicate or is out of order");
+        Self { set }
+    }
+
+    /// Streams the words still consistent with `history`, reusing the same
+    /// green/yellow/gray semantics as [`Guess::matches`].
+    pub fn candidates(&self, history: &[Guess<'_, N>]) -> Vec<String> {
+        let automaton = Constraints::<N>::from_history(history);
+        let mut stream = self.set.search(automaton).into_stream();
+        let mut candidates = Vec::new();
+        while let Some(word) = stream.next() {
+            candidates.push(std::str::from_utf8(word).expect("dictionary words are ASCII").to_owned());
+        }
+        candidates
+    }
+}
+
+impl Default for FstIndex<5> {
+    fn default() -> Self {
+        Self::new(crate::DICTIONARY.iter().map(|&(word, _)| word))
+    }
+}
+
+/// Per-letter occurrence bounds derived from a guess history: which letter
+/// (if any) is pinned at each position, which positions a letter is known
+/// not to occupy, which letters are ruled out entirely, and the
+/// minimum/maximum number of times each letter may occur. A gray tile's
+/// maximum comes from however many copies of that letter were confirmed
+/// green/yellow elsewhere in the same guess.
+struct Constraints<const N: usize> {
+    required: [Option<u8>; N],
+    not_at: [[bool; 26]; N],
+    forbidden: [bool; 26],
+    min_count: [u8; 26],
+    max_count: [u8; 26],
+}
+
+impl<const N: usize> Constraints<N> {
+    fn from_history(history: &[Guess<'_, N>]) -> Self {
+        let mut required: [Option<u8>; N] = [None; N];
+        let mut not_at = [[false; 26]; N];
+        let mut forbidden = [false; 26];
+        let mut min_count = [0_u8; 26];
+        let mut max_count = [u8::MAX; 26];
+
+        for guess in history {
+            let mut seen = [0_u8; 26];
+
+            // First pass: lock in green positions, tally up how many
+            // green/yellow copies of each letter this guess confirmed, and
+            // rule out every position a non-green tile disproves — mirrors
+            // `Guess::matches`'s first loop, which rejects a candidate with
+            // the guessed letter at a position whose mask isn't `Correct`.
+            for (i, (b, c)) in guess.word.bytes().zip(guess.mask.iter()).enumerate() {
+                let idx = (b - b'a') as usize;
+                match c {
+                    Correctness::Correct => {
+                        required[i] = Some(b);
+                        seen[idx] += 1;
+                    }
+                    Correctness::Misplaced => {
+                        seen[idx] += 1;
+                        not_at[i][idx] = true;
+                    }
+                    Correctness::Wrong => not_at[i][idx] = true,
+                }
+            }
+
+            // Second pass: a gray tile caps the letter at exactly how many
+            // copies were confirmed above; anything still at zero is ruled
+            // out everywhere.
+            for (b, c) in guess.word.bytes().zip(guess.mask.iter()) {
+                let idx = (b - b'a') as usize;
+                if *c == Correctness::Wrong {
+                    max_count[idx] = max_count[idx].min(seen[idx]);
+                    if seen[idx] == 0 {
+                        forbidden[idx] = true;
+                    }
+                } else {
+                    min_count[idx] = min_count[idx].max(seen[idx]);
+                }
+            }
+        }
+
+        Self { required, not_at, forbidden, min_count, max_count }
+    }
+}
+
+#[derive(Clone)]
+struct ConstraintState {
+    depth: usize,
+    counts: [u8; 26],
+    dead: bool,
+}
+
+impl<const N: usize> Automaton for Constraints<N> {
+    type State = ConstraintState;
+
+    fn start(&self) -> Self::State {
+        ConstraintState { depth: 0, counts: [0; 26], dead: false }
+    }
+
+    fn is_match(&self, state: &Self::State) -> bool {
+        !state.dead
+            && state.depth == N
+            && (0..26).all(|letter| state.counts[letter] >= self.min_count[letter])
+    }
+
+    fn can_match(&self, state: &Self::State) -> bool {
+        !state.dead
+    }
+
+    fn accept(&self, state: &Self::State, byte: u8) -> Self::State {
+        if state.dead || state.depth >= N {
+            return ConstraintState { dead: true, ..state.clone() };
+        }
+
+        let idx = (byte - b'a') as usize;
+        if self.forbidden[idx] {
+            return ConstraintState { dead: true, ..state.clone() };
+        }
+        if let Some(required) = self.required[state.depth] {
+            if required != byte {
+                return ConstraintState { dead: true, ..state.clone() };
+            }
+        } else if self.not_at[state.depth][idx] {
+            return ConstraintState { dead: true, ..state.clone() };
+        }
+
+        let mut counts = state.counts;
+        counts[idx] += 1;
+        if counts[idx] > self.max_count[idx] {
+            return ConstraintState { dead: true, counts, depth: state.depth + 1 };
+        }
+
+        ConstraintState { depth: state.depth + 1, counts, dead: false }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+
+    const FIXTURE: &[(&str, usize)] = &[
+        ("moths", 1),
+        ("ssimp", 1),
+        ("sssim", 1),
+        ("stoic", 1),
+        ("swamp", 1),
+    ];
+
+    #[test]
+    fn candidates_enforces_required_position_repeat_caps_and_forbidden_letters() {
+        let index = FstIndex::<5>::new(FIXTURE.iter().map(|&(word, _)| word));
+
+        // 's' is confirmed at position 0 and again (misplaced) later, so
+        // exactly two 's's are required; 'a' and 'y' only ever showed up
+        // `Wrong`, so they're forbidden outright.
+        let history = vec![Guess {
+            word: Cow::Borrowed("sassy"),
+            mask: [
+                Correctness::Correct,
+                Correctness::Wrong,
+                Correctness::Wrong,
+                Correctness::Misplaced,
+                Correctness::Wrong,
+            ],
+        }];
+
+        let candidates = index.candidates(&history);
+
+        // "moths" fails the required first letter; "sssim" has three 's's,
+        // over the cap; "stoic" has only one 's', under the floor; "swamp"
+        // contains the forbidden 'a'. Only "ssimp" satisfies every
+        // constraint.
+        assert_eq!(candidates, vec!["ssimp"]);
+    }
+
+    #[test]
+    fn candidates_rejects_a_disproven_letter_recurring_at_the_same_position() {
+        const FIXTURE: &[&str] = &["sbbsc", "ssimp"];
+        let index = FstIndex::<5>::new(FIXTURE.iter().copied());
+
+        // Position 3 is confirmed `Wrong` for 's' even though an 's' exists
+        // elsewhere (misplaced), so no candidate may have 's' at position 3
+        // — exactly what `Guess::matches` enforces in its first loop.
+        let history = vec![Guess {
+            word: Cow::Borrowed("sassy"),
+            mask: [
+                Correctness::Correct,
+                Correctness::Wrong,
+                Correctness::Misplaced,
+                Correctness::Wrong,
+                Correctness::Wrong,
+            ],
+        }];
+
+        assert!(!history[0].matches("sbbsc"), "test fixture contradicts Guess::matches");
+
+        let candidates = index.candidates(&history);
+
+        assert_eq!(candidates, vec!["ssimp"]);
+    }
+}
@@ -0,0 +1,10 @@
+//! Strategies for picking the next guess, and the supporting machinery they
+//! share (candidate enumeration, scoring, etc).
+
+mod entropy;
+mod fst_index;
+mod naive;
+
+pub use entropy::{Entropy, ExplorationPool};
+pub use fst_index::FstIndex;
+pub use naive::Naive;
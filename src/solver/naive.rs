@@ -0,0 +1,114 @@
+//! A baseline reference [`Guesser`]: prune the candidate set with
+//! [`FstIndex`] and return the first survivor.
+
+use std::collections::HashMap;
+
+use crate::{Guess, Guesser, Word, Wordle};
+
+use super::FstIndex;
+
+pub struct Naive<const N: usize = 5> {
+    index: FstIndex<N>,
+    handles: HashMap<&'static str, Word>,
+}
+
+impl<const N: usize> Naive<N> {
+    /// Compiles `wordle`'s own answer list into an [`FstIndex`], so
+    /// narrowing survivors each turn is proportional to shared prefixes
+    /// rather than a full scan, and keeps a lookup back to each word's
+    /// already-validated [`Word`] handle so guessing never has to
+    /// re-validate a candidate on the hot path. Pruning against `answers`
+    /// rather than the larger `allowed_guesses` keeps every survivor a word
+    /// that could actually be the hidden answer.
+    pub fn new(wordle: &Wordle<N>) -> Self {
+        let entries: Vec<(Word, &'static str)> =
+            wordle.answers().map(|(handle, word, _)| (handle, word)).collect();
+        let index = FstIndex::new(entries.iter().map(|&(_, word)| word));
+        let handles = entries.into_iter().map(|(handle, word)| (word, handle)).collect();
+        Self { index, handles }
+    }
+}
+
+impl<const N: usize> Guesser<N> for Naive<N> {
+    fn guess(&mut self, history: &[Guess<'_, N>]) -> Word {
+        // `FstIndex`'s `Constraints` automaton already forbids any letter
+        // disproven (entirely or at a given position) anywhere in
+        // `history`, so every survivor here already refuses to recycle a
+        // dead letter — no separate check is needed on top of it.
+        let candidates = self.index.candidates(history);
+        let word = candidates
+            .first()
+            .expect("some candidate is always still consistent with history");
+        self.handles
+            .get(word.as_str())
+            .copied()
+            .expect("FstIndex only yields words from this Naive's own answer list")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Correctness;
+
+    const ALLOWED: &[(&str, usize)] = &[("sassy", 1), ("fghij", 1), ("aabbc", 1)];
+
+    #[test]
+    fn guess_never_reproposes_a_word_containing_a_confirmed_dead_letter() {
+        let wordle: Wordle = Wordle::new(ALLOWED, ALLOWED);
+        let mut naive = Naive::new(&wordle);
+
+        let history = vec![Guess {
+            word: "aabbc".into(),
+            mask: [Correctness::Wrong; 5],
+        }];
+
+        let guess = wordle.resolve(naive.guess(&history));
+
+        assert!(
+            !guess.bytes().any(|letter| "aabbc".bytes().any(|dead| dead == letter)),
+            "guess '{guess}' reuses a letter already confirmed dead"
+        );
+    }
+
+    #[test]
+    fn every_guess_matches_the_history_it_was_chosen_against() {
+        // The true answer repeats a letter ('s' three times), so this
+        // exercises `Correctness::compute` producing a repeated-letter mask,
+        // not just the all-distinct-letters "fghij" case.
+        let wordle: Wordle = Wordle::new(ALLOWED, ALLOWED);
+        let mut naive = Naive::new(&wordle);
+        let mut history: Vec<Guess> = Vec::new();
+
+        for _ in 0..ALLOWED.len() {
+            let guess = wordle.resolve(naive.guess(&history));
+            assert!(
+                history.iter().all(|past| past.matches(guess)),
+                "guess '{guess}' contradicts a tile already revealed in history"
+            );
+            if guess == "sassy" {
+                return;
+            }
+            history.push(Guess { word: guess.into(), mask: Correctness::compute::<5>("sassy", guess) });
+        }
+    }
+
+    #[test]
+    fn converges_to_the_answer_within_the_candidate_set() {
+        // The true answer repeats a letter ('s' three times), so this
+        // exercises `Correctness::compute` producing a repeated-letter mask,
+        // not just the all-distinct-letters "fghij" case.
+        let wordle: Wordle = Wordle::new(ALLOWED, ALLOWED);
+        let mut naive = Naive::new(&wordle);
+        let mut history: Vec<Guess> = Vec::new();
+
+        for _ in 0..ALLOWED.len() {
+            let guess = wordle.resolve(naive.guess(&history));
+            if guess == "sassy" {
+                return;
+            }
+            history.push(Guess { word: guess.into(), mask: Correctness::compute::<5>("sassy", guess) });
+        }
+        panic!("naive never converged on the answer within the candidate set");
+    }
+}